@@ -0,0 +1,5 @@
+/// `vendor_id` is constant for all variations of HackRF
+pub const VENDOR_ID: u16 = 0x1d50;
+
+pub const TRANSFERS_NUM: usize = 4;
+pub const TRANSFER_SIZE: usize = 262_144;
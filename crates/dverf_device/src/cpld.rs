@@ -0,0 +1,61 @@
+use crate::{
+	Device, Error, Result,
+	consts::TRANSFER_SIZE,
+	device::VendorRequest,
+	transport::Transport,
+	ty::TransceiverMode,
+};
+
+impl<T: Transport> Device<T> {
+	/// Switches the board into [`TransceiverMode::CpldUpdate`], streams
+	/// `bitstream` over the bulk-out endpoint in [`TRANSFER_SIZE`] chunks,
+	/// then reads back the firmware-computed checksum via `CpldChecksum` and
+	/// compares it against a CRC-32 computed on the host.
+	///
+	/// Borrows the "verify before you trust the swap" pattern from firmware
+	/// updates: a checksum mismatch means the CPLD image did not take, and
+	/// is reported as [`Error::ChecksumMismatch`] rather than silently
+	/// leaving the CPLD half-programmed.
+	pub async fn update_cpld(&mut self, bitstream: &[u8]) -> Result<()> {
+		self.set_transceiver_mode(TransceiverMode::CpldUpdate).await?;
+
+		for chunk in bitstream.chunks(TRANSFER_SIZE) {
+			self.submit_bulk_out(chunk.to_vec()).await?;
+		}
+
+		let reported = self.cpld_checksum().await?;
+		let expected = crc32(bitstream);
+
+		if reported != expected {
+			return Err(Error::ChecksumMismatch { expected, reported });
+		}
+
+		Ok(())
+	}
+
+	/// Reads the checksum the CPLD firmware computed over the bitstream it
+	/// just received
+	pub async fn cpld_checksum(&self) -> Result<u32> {
+		let data = self.control_in(VendorRequest::CpldChecksum, 0, 0, 4).await?;
+
+		let bytes: [u8; 4] = data.try_into().map_err(|_| Error::Resp)?;
+
+		Ok(u32::from_le_bytes(bytes))
+	}
+}
+
+/// CRC-32 (IEEE 802.3), mirroring the checksum the CPLD firmware reports
+fn crc32(data: &[u8]) -> u32 {
+	let mut crc = 0xFFFF_FFFFu32;
+
+	for &byte in data {
+		crc ^= u32::from(byte);
+
+		for _ in 0..8 {
+			let mask = (crc & 1).wrapping_neg();
+			crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+		}
+	}
+
+	!crc
+}
@@ -1,57 +1,53 @@
 use std::{
 	fmt::{Debug, Formatter},
-	mem,
 	mem::ManuallyDrop,
 	pin::Pin,
 	task::{Context, Poll},
 };
 
-use futures_lite::{Stream, ready};
+use futures_lite::{Stream, future::poll_fn, ready};
 use num_enum::IntoPrimitive;
-use nusb::transfer::{ControlIn, ControlOut, ControlType, Queue, Recipient, RequestBuffer};
 
 use crate::{
 	BoardId, BoardRev, Error, Result,
-	consts::{TRANSFER_SIZE, TRANSFERS_NUM},
+	transport::{NusbTransport, Transport},
 	ty,
 	ty::Sample,
 };
 
-pub struct Device {
-	usb_if: nusb::Interface,
-	bulk_in: Queue<RequestBuffer>,
-	bulk_out: Queue<Vec<u8>>,
+pub struct Device<T: Transport = NusbTransport> {
+	transport: T,
 }
 
-impl Debug for Device {
+impl<T: Transport> Debug for Device<T> {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-		f.debug_struct("Device")
-			.field("usb_if", &format_args!("Interface {{ .. }}"))
-			.field("bulk_in", &format_args!("Queue::<RequestBuffer> {{ .. }}"))
-			.field("bulk_out", &format_args!("Queue::<Vec<u8>> {{ .. }}"))
-			.finish()
+		f.debug_struct("Device").field("transport", &format_args!("{{ .. }}")).finish()
 	}
 }
 
-impl Device {
+impl Device<NusbTransport> {
 	pub fn from_interface(usb_if: nusb::Interface) -> Self {
-		Self {
-			bulk_in: usb_if.bulk_in_queue(0x81),
-			bulk_out: usb_if.bulk_out_queue(0x02),
-			usb_if,
-		}
+		Self { transport: NusbTransport::new(usb_if) }
 	}
 
 	pub fn into_interface(self) -> nusb::Interface {
-		self.usb_if
+		self.transport.into_interface()
+	}
+}
+
+impl<T: Transport> Device<T> {
+	/// Builds a [`Device`] directly from a [`Transport`], bypassing real USB
+	/// entirely; this is how a [`crate::mock::MockTransport`] gets wired in
+	pub fn from_transport(transport: T) -> Self {
+		Self { transport }
 	}
 
 	pub async fn reset(&self) -> Result<()> {
-		control_out(&self.usb_if, VendorRequest::Reset, 0, 0, &[]).await
+		self.transport.control_out(VendorRequest::Reset, 0, 0, &[]).await
 	}
 
 	pub async fn board_id(&self) -> Result<BoardId> {
-		let res = control_in(&self.usb_if, VendorRequest::BoardIdRead, 0, 0, 1).await?;
+		let res = self.transport.control_in(VendorRequest::BoardIdRead, 0, 0, 1).await?;
 
 		let Some(&first) = res.first() else {
 			return Err(Error::Resp);
@@ -61,7 +57,7 @@ impl Device {
 	}
 
 	pub async fn board_rev(&self) -> Result<BoardRev> {
-		let res = control_in(&self.usb_if, VendorRequest::BoardRevRead, 0, 0, 1).await?;
+		let res = self.transport.control_in(VendorRequest::BoardRevRead, 0, 0, 1).await?;
 
 		let Some(&first) = res.first() else {
 			return Err(Error::Resp);
@@ -71,12 +67,12 @@ impl Device {
 	}
 
 	pub async fn version(&self) -> Result<String> {
-		let nullstr = control_in(&self.usb_if, VendorRequest::VersionStringRead, 0, 0, 255).await?;
+		let nullstr = self.transport.control_in(VendorRequest::VersionStringRead, 0, 0, 255).await?;
 		Ok(String::from_utf8_lossy(&nullstr).into_owned())
 	}
 
 	pub async fn set_transceiver_mode(&self, mode: ty::TransceiverMode) -> Result<()> {
-		control_in(&self.usb_if, VendorRequest::SetTransceiverMode, 0, mode.into(), 0).await.and(Ok(()))
+		self.transport.control_in(VendorRequest::SetTransceiverMode, 0, mode.into(), 0).await.and(Ok(()))
 	}
 
 	pub async fn set_freq(&self, mhz: u32, hz: u32) -> Result<()> {
@@ -85,20 +81,15 @@ impl Device {
 		data[..4].copy_from_slice(&mhz_le);
 		data[4..].copy_from_slice(&hz_le);
 
-		control_out(&self.usb_if, VendorRequest::SetFreq, 0, 0, &data).await?;
+		self.transport.control_out(VendorRequest::SetFreq, 0, 0, &data).await?;
 
 		Ok(())
 	}
 
 	pub async fn set_baseband_filter_bandwidth(&self, hz: u32) -> Result<()> {
-		control_out(
-			&self.usb_if,
-			VendorRequest::SetBasebandFilterBandwidth,
-			(hz >> 16) as u16,
-			(hz & 0xffff) as u16,
-			&[],
-		)
-		.await
+		self.transport
+			.control_out(VendorRequest::SetBasebandFilterBandwidth, (hz >> 16) as u16, (hz & 0xffff) as u16, &[])
+			.await
 	}
 
 	pub async fn set_sample_rate(&self, hz: u32, divider: u32) -> Result<()> {
@@ -107,11 +98,11 @@ impl Device {
 		data[..4].copy_from_slice(&hz_le);
 		data[4..].copy_from_slice(&divider_le);
 
-		control_out(&self.usb_if, VendorRequest::SetSampleRate, 0, 0, &data).await
+		self.transport.control_out(VendorRequest::SetSampleRate, 0, 0, &data).await
 	}
 
 	pub async fn set_lna_gain(&self, value: u16) -> Result<()> {
-		let res = control_in(&self.usb_if, VendorRequest::SetLnaGain, value, 0, 1).await?;
+		let res = self.transport.control_in(VendorRequest::SetLnaGain, value, 0, 1).await?;
 
 		let Some(&first) = res.first() else {
 			return Err(Error::Resp);
@@ -121,7 +112,7 @@ impl Device {
 	}
 
 	pub async fn set_vga_gain(&self, value: u16) -> Result<()> {
-		let res = control_in(&self.usb_if, VendorRequest::SetVgaGain, value, 0, 1).await?;
+		let res = self.transport.control_in(VendorRequest::SetVgaGain, value, 0, 1).await?;
 
 		let Some(&first) = res.first() else {
 			return Err(Error::Resp);
@@ -129,66 +120,56 @@ impl Device {
 
 		if first != 1 { Err(Error::Param("Vga Gain")) } else { Ok(()) }
 	}
-}
 
-async fn control_in(interface: &nusb::Interface, vrid: VendorRequest, index: u16, value: u16, length: u16) -> Result<Vec<u8>> {
-	let data = ControlIn {
-		control_type: ControlType::Vendor,
-		recipient: Recipient::Device,
-		request: vrid.into(),
-		index,
-		value,
-		length,
-	};
-
-	let result = interface.control_in(data).await;
-	result.status?;
-	Ok(result.data)
-}
+	/// Raw bytes of the M0 coprocessor's state report (layout is
+	/// firmware-defined); a successful response means the coprocessor is
+	/// alive and answering control requests
+	pub async fn m0_state(&self) -> Result<Vec<u8>> {
+		self.transport.control_in(VendorRequest::GetM0State, 0, 0, 32).await
+	}
 
-async fn control_out(interface: &nusb::Interface, vrid: VendorRequest, index: u16, value: u16, data: &[u8]) -> Result<()> {
-	let data = ControlOut {
-		control_type: ControlType::Vendor,
-		recipient: Recipient::Device,
-		request: vrid.into(),
-		index,
-		value,
-		data,
-	};
-
-	let result = interface.control_out(data).await;
-	result.status?;
-	Ok(())
-}
+	pub(crate) async fn control_in(&self, vrid: VendorRequest, index: u16, value: u16, length: u16) -> Result<Vec<u8>> {
+		self.transport.control_in(vrid, index, value, length).await
+	}
 
-impl Device {
-	pub fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<ty::Sample>>>> {
-		while self.bulk_in.pending() < TRANSFERS_NUM {
-			self.bulk_in.submit(RequestBuffer::new(TRANSFER_SIZE));
-		}
+	pub(crate) async fn control_out(&self, vrid: VendorRequest, index: u16, value: u16, data: &[u8]) -> Result<()> {
+		self.transport.control_out(vrid, index, value, data).await
+	}
 
-		let mut completion = ready!(self.bulk_in.poll_next(cx));
+	pub(crate) fn poll_next_raw(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<u8>>>> {
+		self.transport.poll_bulk_in(cx)
+	}
 
-		if let Err(err) = completion.status {
-			return Poll::Ready(Some(Err(err.into())));
-		}
+	pub fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<ty::Sample>>>> {
+		let data = match ready!(self.poll_next_raw(cx)) {
+			Some(Ok(data)) => data,
+			Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+			None => return Poll::Ready(None),
+		};
 
-		let data = mem::replace(&mut completion.data, Vec::with_capacity(TRANSFER_SIZE));
-		let mut data = ManuallyDrop::new(data);
+		Poll::Ready(Some(Ok(bytes_into_samples(data))))
+	}
 
-		let len = data.len() / 2;
-		let cap = data.capacity() / 2;
-		let ptr = data.as_mut_ptr() as *mut Sample;
+	/// Submits `chunk` on the bulk-out endpoint and waits for the transfer to
+	/// complete before returning, used by one-shot transfers like
+	/// [`Device::update_cpld`] rather than the pipelined sample path
+	pub(crate) async fn submit_bulk_out(&mut self, chunk: Vec<u8>) -> Result<()> {
+		self.transport.submit_bulk_out(chunk);
+		poll_fn(|cx| self.transport.poll_bulk_out(cx)).await
+	}
+}
 
-		let chunk = unsafe { Vec::from_raw_parts(ptr, len, cap) };
+pub(crate) fn bytes_into_samples(data: Vec<u8>) -> Vec<Sample> {
+	let mut data = ManuallyDrop::new(data);
 
-		self.bulk_in.submit(RequestBuffer::reuse(completion.data, TRANSFER_SIZE));
+	let len = data.len() / 2;
+	let cap = data.capacity() / 2;
+	let ptr = data.as_mut_ptr() as *mut Sample;
 
-		Poll::Ready(Some(Ok(chunk)))
-	}
+	unsafe { Vec::from_raw_parts(ptr, len, cap) }
 }
 
-impl Stream for Device {
+impl<T: Transport> Stream for Device<T> {
 	type Item = Result<Vec<Sample>>;
 
 	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
@@ -199,7 +180,7 @@ impl Stream for Device {
 #[allow(unused)]
 #[derive(Debug, Copy, Clone, IntoPrimitive)]
 #[repr(u8)]
-enum VendorRequest {
+pub(crate) enum VendorRequest {
 	SetTransceiverMode = 1,
 	Max2837Write = 2,
 	Max2837Read = 3,
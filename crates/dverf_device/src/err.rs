@@ -12,4 +12,14 @@ pub enum Error {
 
 	#[error("Invalid value for parameter: {0}")]
 	Param(&'static str),
+
+	/// Read-back comparison during a firmware write found a mismatch at this
+	/// byte offset into the image
+	#[error("Firmware verify failed at offset {offset}")]
+	Verify { offset: usize },
+
+	/// [`crate::Device::update_cpld`] read back a checksum that didn't match
+	/// what was computed on the host; the CPLD image did not take
+	#[error("CPLD checksum mismatch: expected {expected:#010x}, device reported {reported:#010x}")]
+	ChecksumMismatch { expected: u32, reported: u32 },
 }
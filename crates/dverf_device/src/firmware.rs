@@ -0,0 +1,88 @@
+use crate::{
+	Device, Error, Result,
+	device::VendorRequest,
+	transport::Transport,
+};
+
+/// Max payload for a single `SpiFlashRead`/`SpiFlashWrite` control transfer
+const FLASH_CHUNK_SIZE: usize = 64;
+
+/// Write-in-progress bit of the flash status register
+const FLASH_STATUS_WIP: u8 = 0x01;
+
+impl<T: Transport> Device<T> {
+	/// Writes a full firmware image to the SPI flash the way a diagnostic
+	/// flasher does: erase the flash, stream `image` in page-sized writes
+	/// polling [`Device::flash_status`] between pages, then read the whole
+	/// region back and compare it to `image`, returning
+	/// [`Error::Verify`] on the first mismatch. `progress(written, total)` is
+	/// called after each page is written.
+	pub async fn write_firmware(&self, image: &[u8], mut progress: impl FnMut(usize, usize)) -> Result<()> {
+		self.erase_flash().await?;
+		self.await_flash_write().await?;
+
+		let mut written = 0;
+
+		for (i, page) in image.chunks(FLASH_CHUNK_SIZE).enumerate() {
+			let address = (i * FLASH_CHUNK_SIZE) as u32;
+
+			self.control_out(VendorRequest::SpiFlashWrite, (address & 0xffff) as u16, (address >> 16) as u16, page).await?;
+
+			self.await_flash_write().await?;
+
+			written += page.len();
+			progress(written, image.len());
+		}
+
+		let readback = self.read_firmware(image.len()).await?;
+
+		if let Some(offset) = readback.iter().zip(image).position(|(a, b)| a != b) {
+			self.control_out(VendorRequest::SpiFlashClearStatus, 0, 0, &[]).await?;
+			return Err(Error::Verify { offset });
+		}
+
+		Ok(())
+	}
+
+	/// Reads `len` bytes of the SPI flash starting at address 0
+	pub async fn read_firmware(&self, len: usize) -> Result<Vec<u8>> {
+		let mut out = Vec::with_capacity(len);
+		let mut address = 0u32;
+		let mut remaining = len;
+
+		while remaining > 0 {
+			let chunk_len = remaining.min(FLASH_CHUNK_SIZE);
+
+			let data =
+				self.control_in(VendorRequest::SpiFlashRead, (address & 0xffff) as u16, (address >> 16) as u16, chunk_len as u16).await?;
+
+			out.extend_from_slice(&data);
+
+			address += chunk_len as u32;
+			remaining -= chunk_len;
+		}
+
+		Ok(out)
+	}
+
+	async fn erase_flash(&self) -> Result<()> {
+		self.control_out(VendorRequest::SpiFlashErase, 0, 0, &[]).await
+	}
+
+	/// Reads the one-byte flash status register; bit 0 is write-in-progress
+	pub async fn flash_status(&self) -> Result<u8> {
+		let res = self.control_in(VendorRequest::SpiFlashStatus, 0, 0, 1).await?;
+
+		let Some(&status) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		Ok(status)
+	}
+
+	async fn await_flash_write(&self) -> Result<()> {
+		while self.flash_status().await? & FLASH_STATUS_WIP != 0 {}
+
+		Ok(())
+	}
+}
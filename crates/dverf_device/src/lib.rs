@@ -1,9 +1,33 @@
+//! A from-scratch reimplementation of the [`dverf`](../dverf/index.html)
+//! Device around a [`Transport`] trait, so the sample pipeline and
+//! control-request logic can be driven by [`mock::MockTransport`] instead of
+//! real hardware (see [`dverf_app`]'s `--demo` mode).
+//!
+//! This crate is currently scoped to the subset of `dverf` needed for
+//! firmware/CPLD updates and frequency-sweep capture; it does not (yet)
+//! cover Opera Cake, Si5351C clocking, or the TX gain/bias-tee controls that
+//! `dverf` has. Until those land here, `dverf` remains the crate real
+//! applications should build against for anything outside that subset —
+//! `dverf_device` is not a drop-in replacement, and the two `Device` types
+//! are not expected to stay in lockstep bug-for-bug (each one's flash
+//! address and sweep framing bugs have already had to be fixed
+//! independently). Porting a `dverf` feature here should also remove it from
+//! `dverf`, rather than letting the two drift further apart.
+
 mod consts;
+mod cpld;
 pub mod device;
 mod err;
+mod firmware;
+pub mod mock;
+mod sweep;
+pub mod transport;
 pub mod ty;
 
 pub use consts::VENDOR_ID;
 pub use device::Device;
 pub use err::{Error, Result};
+pub use mock::MockTransport;
+pub use sweep::{SWEEP_BLOCK_SIZE, Sweep, SweepDwell, SweepTile};
+pub use transport::{NusbTransport, Transport};
 pub use ty::*;
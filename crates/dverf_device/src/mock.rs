@@ -0,0 +1,129 @@
+use std::{
+	collections::VecDeque,
+	task::{Context, Poll, Waker},
+};
+
+use crate::{
+	BoardId, BoardRev, Result,
+	device::VendorRequest,
+	transport::Transport,
+};
+
+/// A [`Transport`] that never touches real hardware, for driving
+/// [`crate::Device`]'s sample pipeline and control-request logic in tests.
+/// Control requests are answered from canned board identity/version fields;
+/// bulk-in data is whatever was queued with [`MockTransport::push_bulk_in`]
+pub struct MockTransport {
+	board_id: BoardId,
+	board_rev: BoardRev,
+	version: String,
+	bulk_in: VecDeque<Vec<u8>>,
+	waker: Option<Waker>,
+}
+
+impl Default for MockTransport {
+	fn default() -> Self {
+		Self {
+			board_id: BoardId::HackrfOneOg,
+			board_rev: BoardRev::R9,
+			version: "mock".to_owned(),
+			bulk_in: VecDeque::new(),
+			waker: None,
+		}
+	}
+}
+
+impl MockTransport {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn with_board(mut self, board_id: BoardId, board_rev: BoardRev) -> Self {
+		self.board_id = board_id;
+		self.board_rev = board_rev;
+		self
+	}
+
+	pub fn with_version(mut self, version: impl Into<String>) -> Self {
+		self.version = version.into();
+		self
+	}
+
+	/// Queues a synthetic I/Q buffer to be handed out by the next
+	/// [`Transport::poll_bulk_in`] poll, waking a pending poll if one is
+	/// parked waiting for data
+	pub fn push_bulk_in(&mut self, data: Vec<u8>) {
+		self.bulk_in.push_back(data);
+
+		if let Some(waker) = self.waker.take() {
+			waker.wake();
+		}
+	}
+}
+
+impl Transport for MockTransport {
+	async fn control_in(&self, vrid: VendorRequest, _index: u16, _value: u16, _length: u16) -> Result<Vec<u8>> {
+		Ok(match vrid {
+			VendorRequest::BoardIdRead => vec![self.board_id.into()],
+			VendorRequest::BoardRevRead => vec![self.board_rev.into()],
+			VendorRequest::VersionStringRead => self.version.clone().into_bytes(),
+			VendorRequest::SetTransceiverMode | VendorRequest::SetLnaGain | VendorRequest::SetVgaGain => vec![1],
+			_ => Vec::new(),
+		})
+	}
+
+	async fn control_out(&self, _vrid: VendorRequest, _index: u16, _value: u16, _data: &[u8]) -> Result<()> {
+		Ok(())
+	}
+
+	fn poll_bulk_in(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<u8>>>> {
+		match self.bulk_in.pop_front() {
+			Some(data) => Poll::Ready(Some(Ok(data))),
+			None => {
+				self.waker = Some(cx.waker().clone());
+				Poll::Pending
+			}
+		}
+	}
+
+	fn submit_bulk_out(&mut self, _chunk: Vec<u8>) {}
+
+	fn poll_bulk_out(&mut self, _cx: &mut Context) -> Poll<Result<()>> {
+		Poll::Ready(Ok(()))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use futures_lite::{StreamExt, future::block_on};
+
+	use super::*;
+	use crate::Device;
+
+	#[test]
+	fn reports_configured_board_identity() {
+		let dev = Device::from_transport(MockTransport::new().with_board(BoardId::HackrfOneR9, BoardRev::R10).with_version("1.2.3"));
+
+		block_on(async {
+			assert_eq!(u8::from(dev.board_id().await.unwrap()), u8::from(BoardId::HackrfOneR9));
+			assert_eq!(u8::from(dev.board_rev().await.unwrap()), u8::from(BoardRev::R10));
+			assert_eq!(dev.version().await.unwrap(), "1.2.3");
+		});
+	}
+
+	#[test]
+	fn stream_parks_until_a_buffer_is_pushed_instead_of_ending() {
+		let mut transport = MockTransport::new();
+		transport.push_bulk_in(vec![1, 0, 2, 0, 3, 0]);
+
+		let mut dev = Device::from_transport(transport);
+
+		block_on(async {
+			let samples = dev.next().await.unwrap().unwrap();
+			assert_eq!(samples.len(), 3);
+
+			// Nothing is queued, but the stream must keep polling rather than end
+			assert!(futures_lite::future::poll_once(dev.next()).await.is_none());
+		});
+	}
+}
@@ -0,0 +1,153 @@
+use std::{
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+use futures_lite::{Stream, ready};
+
+use crate::{
+	Device, Error, Result,
+	device::{VendorRequest, bytes_into_samples},
+	transport::Transport,
+	ty::Sample,
+};
+
+/// Size of one bulk block the firmware sends while sweeping, header
+/// included; the `dwell` passed to [`Device::init_sweep`] must be a multiple
+/// of this
+pub const SWEEP_BLOCK_SIZE: usize = 16_384;
+
+/// Sync bytes + little-endian `u64` frequency the firmware prepends to every
+/// bulk block while sweeping
+const SWEEP_HEADER_LEN: usize = 10;
+const SWEEP_SYNC: [u8; 2] = [0x7f, 0x7f];
+
+/// How many sample bytes to capture at each tuned frequency before stepping
+/// to the next one, passed to [`Device::init_sweep`]; must be a multiple of
+/// [`SWEEP_BLOCK_SIZE`]
+#[derive(Debug, Copy, Clone)]
+pub struct SweepDwell(pub u32);
+
+/// A block of samples captured at a single tuned frequency during a sweep,
+/// yielded by [`Device::sweep`]
+#[derive(Debug)]
+pub struct SweepTile {
+	pub center_hz: u64,
+	pub samples: Vec<Sample>,
+}
+
+impl<T: Transport> Device<T> {
+	/// Primes the device for frequency-sweep reception.
+	///
+	/// `ranges` is a list of `(low_mhz, high_mhz)` windows to sweep across,
+	/// `step_hz` is the tuning step within each window, and `dwell` is how
+	/// many sample bytes to capture per frequency before stepping.
+	///
+	/// Follow up with [`Device::set_transceiver_mode`] using
+	/// [`crate::TransceiverMode::RxSweep`], then poll [`Device::sweep`].
+	pub async fn init_sweep(&self, ranges: &[(u16, u16)], step_hz: u32, dwell: SweepDwell) -> Result<()> {
+		if dwell.0 as usize % SWEEP_BLOCK_SIZE != 0 {
+			return Err(Error::Param("dwell"));
+		}
+
+		let mut data = Vec::with_capacity(13 + ranges.len() * 4);
+
+		data.extend_from_slice(&step_hz.to_le_bytes());
+		data.extend_from_slice(&dwell.0.to_le_bytes());
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data.push(0);
+
+		for &(low_mhz, high_mhz) in ranges {
+			data.extend_from_slice(&low_mhz.to_le_bytes());
+			data.extend_from_slice(&high_mhz.to_le_bytes());
+		}
+
+		self.control_out(VendorRequest::InitSweep, 0, 0, &data).await
+	}
+
+	/// Returns a [`Stream`] of [`SweepTile`]s for a sweep started with
+	/// [`Device::init_sweep`].
+	///
+	/// Every USB block received while in [`crate::TransceiverMode::RxSweep`]
+	/// is prefixed with a 10-byte header: two `0x7f` sync bytes followed by
+	/// the tuned frequency as a little-endian `u64` in Hz. This strips that
+	/// header and yields the remaining bytes of the block reinterpreted as
+	/// samples, keyed by the frequency they were captured at. A single
+	/// transfer may contain several blocks, and a header split across two
+	/// transfers is buffered until it can be parsed in full.
+	pub fn sweep(&mut self) -> Sweep<'_, T> {
+		Sweep { device: self, carry: Vec::new() }
+	}
+}
+
+pub struct Sweep<'a, T: Transport> {
+	device: &'a mut Device<T>,
+	carry: Vec<u8>,
+}
+
+impl<T: Transport> Sweep<'_, T> {
+	fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<SweepTile>>> {
+		loop {
+			if self.carry.len() >= SWEEP_BLOCK_SIZE && self.carry.starts_with(&SWEEP_SYNC) {
+				let center_hz = u64::from_le_bytes(self.carry[2..SWEEP_HEADER_LEN].try_into().unwrap());
+
+				let body_len = SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN;
+				let samples = bytes_into_samples(self.carry[SWEEP_HEADER_LEN..SWEEP_HEADER_LEN + body_len].to_vec());
+
+				self.carry.drain(..SWEEP_HEADER_LEN + body_len);
+
+				return Poll::Ready(Some(Ok(SweepTile { center_hz, samples })));
+			}
+
+			match ready!(self.device.poll_next_raw(cx)) {
+				Some(Ok(data)) => self.carry.extend_from_slice(&data),
+				Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+				None => return Poll::Ready(None),
+			}
+		}
+	}
+}
+
+impl<T: Transport> Stream for Sweep<'_, T> {
+	type Item = Result<SweepTile>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		Self::poll_next(&mut self, cx)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use futures_lite::{StreamExt, future::block_on};
+
+	use super::*;
+	use crate::{Device, MockTransport};
+
+	/// Builds one firmware-style sweep block: sync + frequency header
+	/// followed by `SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN` bytes of sample
+	/// data, all set to `fill`
+	fn block(center_hz: u64, fill: u8) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(SWEEP_BLOCK_SIZE);
+		buf.extend_from_slice(&SWEEP_SYNC);
+		buf.extend_from_slice(&center_hz.to_le_bytes());
+		buf.resize(SWEEP_BLOCK_SIZE, fill);
+		buf
+	}
+
+	#[test]
+	fn splits_a_multi_block_transfer_into_one_tile_per_frequency() {
+		let mut transport = MockTransport::new();
+		transport.push_bulk_in([block(2_400_000_000, 0xaa), block(2_500_000_000, 0xbb)].concat());
+
+		let mut dev = Device::from_transport(transport);
+		let mut sweep = dev.sweep();
+
+		let first = block_on(sweep.next()).unwrap().unwrap();
+		assert_eq!(first.center_hz, 2_400_000_000);
+		assert_eq!(first.samples.len(), (SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN) / 2);
+
+		let second = block_on(sweep.next()).unwrap().unwrap();
+		assert_eq!(second.center_hz, 2_500_000_000);
+		assert_eq!(second.samples.len(), (SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN) / 2);
+	}
+}
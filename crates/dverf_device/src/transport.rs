@@ -0,0 +1,93 @@
+use std::task::{Context, Poll};
+
+use futures_lite::ready;
+use nusb::transfer::{ControlIn, ControlOut, ControlType, Queue, Recipient, RequestBuffer};
+
+use crate::{
+	Result,
+	consts::{TRANSFER_SIZE, TRANSFERS_NUM},
+	device::VendorRequest,
+};
+
+/// Abstracts the USB transport [`crate::Device`] is built on, so the sample
+/// pipeline can be exercised without a physical board attached (see
+/// [`crate::mock::MockTransport`])
+pub trait Transport {
+	/// Issues a vendor control-IN request and returns its response payload
+	async fn control_in(&self, vrid: VendorRequest, index: u16, value: u16, length: u16) -> Result<Vec<u8>>;
+
+	/// Issues a vendor control-OUT request carrying `data` as its payload
+	async fn control_out(&self, vrid: VendorRequest, index: u16, value: u16, data: &[u8]) -> Result<()>;
+
+	/// Polls the bulk-in endpoint for the next completed transfer,
+	/// resubmitting as needed to keep samples flowing
+	fn poll_bulk_in(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<u8>>>>;
+
+	/// Queues `chunk` for transmission on the bulk-out endpoint
+	fn submit_bulk_out(&mut self, chunk: Vec<u8>);
+
+	/// Polls for the oldest queued bulk-out transfer to complete
+	fn poll_bulk_out(&mut self, cx: &mut Context) -> Poll<Result<()>>;
+}
+
+/// The real [`Transport`], backed by a claimed [`nusb::Interface`]
+pub struct NusbTransport {
+	usb_if: nusb::Interface,
+	bulk_in: Queue<RequestBuffer>,
+	bulk_out: Queue<Vec<u8>>,
+}
+
+impl NusbTransport {
+	pub fn new(usb_if: nusb::Interface) -> Self {
+		Self { bulk_in: usb_if.bulk_in_queue(0x81), bulk_out: usb_if.bulk_out_queue(0x02), usb_if }
+	}
+
+	pub fn into_interface(self) -> nusb::Interface {
+		self.usb_if
+	}
+}
+
+impl Transport for NusbTransport {
+	async fn control_in(&self, vrid: VendorRequest, index: u16, value: u16, length: u16) -> Result<Vec<u8>> {
+		let data = ControlIn { control_type: ControlType::Vendor, recipient: Recipient::Device, request: vrid.into(), index, value, length };
+
+		let result = self.usb_if.control_in(data).await;
+		result.status?;
+		Ok(result.data)
+	}
+
+	async fn control_out(&self, vrid: VendorRequest, index: u16, value: u16, data: &[u8]) -> Result<()> {
+		let data = ControlOut { control_type: ControlType::Vendor, recipient: Recipient::Device, request: vrid.into(), index, value, data };
+
+		let result = self.usb_if.control_out(data).await;
+		result.status?;
+		Ok(())
+	}
+
+	fn poll_bulk_in(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<u8>>>> {
+		while self.bulk_in.pending() < TRANSFERS_NUM {
+			self.bulk_in.submit(RequestBuffer::new(TRANSFER_SIZE));
+		}
+
+		let mut completion = ready!(self.bulk_in.poll_next(cx));
+
+		if let Err(err) = completion.status {
+			return Poll::Ready(Some(Err(err.into())));
+		}
+
+		let data = std::mem::replace(&mut completion.data, Vec::with_capacity(TRANSFER_SIZE));
+
+		self.bulk_in.submit(RequestBuffer::reuse(completion.data, TRANSFER_SIZE));
+
+		Poll::Ready(Some(Ok(data)))
+	}
+
+	fn submit_bulk_out(&mut self, chunk: Vec<u8>) {
+		self.bulk_out.submit(chunk);
+	}
+
+	fn poll_bulk_out(&mut self, cx: &mut Context) -> Poll<Result<()>> {
+		let completion = ready!(self.bulk_out.poll_next(cx));
+		Poll::Ready(completion.status.map_err(Into::into))
+	}
+}
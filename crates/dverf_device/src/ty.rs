@@ -1,6 +1,6 @@
 use num_enum::{FromPrimitive, IntoPrimitive};
 
-#[derive(Debug, Copy, Clone, FromPrimitive)]
+#[derive(Debug, Copy, Clone, FromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum BoardId {
 	Jellybean = 0,
@@ -12,7 +12,7 @@ pub enum BoardId {
 	Unrecognized = 0xFE,
 }
 
-#[derive(Debug, Copy, Clone, FromPrimitive)]
+#[derive(Debug, Copy, Clone, FromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum BoardRev {
 	Old = 0,
@@ -1,6 +1,7 @@
 #![doc = include_str!("../readme.md")]
 
 use std::{
+	cell::Cell,
 	cmp::Ordering,
 	collections::VecDeque,
 	fmt::{Debug, Display, Formatter},
@@ -17,6 +18,8 @@ pub use nusb;
 use nusb::transfer::{Completion, ControlIn, ControlOut, ControlType, Queue, Recipient, RequestBuffer};
 use thiserror::Error;
 
+mod ring;
+
 pub mod internals {
 	use std::{mem::ManuallyDrop, slice};
 
@@ -109,6 +112,16 @@ pub enum Error {
 
 	#[error("Invalid value for parameter: {0}")]
 	Param(&'static str),
+
+	/// The [`Device::rx_ring`] consumer didn't keep up and the writer had to
+	/// drop a completion rather than overwrite unread samples
+	#[error("Rx ring overrun")]
+	RxOverrun,
+
+	/// [`Device::write_flash`] read the image back and it didn't match what
+	/// was written
+	#[error("Flash verify failed at offset {offset}")]
+	FlashVerify { offset: usize },
 }
 
 /// Main interface to a HackRF One device
@@ -126,6 +139,8 @@ pub struct Device {
 	current_buffer: Vec<u8>,
 	free_buffers: Vec<Vec<u8>>,
 	pending_buffers: VecDeque<Vec<u8>>,
+
+	flash_state: Cell<FlashState>,
 }
 
 impl Device {
@@ -159,6 +174,8 @@ impl Device {
 			free_buffers: Vec::with_capacity(TRANSFER_COUNT),
 
 			pending_buffers: VecDeque::new(),
+
+			flash_state: Cell::new(FlashState::Idle),
 		}
 	}
 
@@ -284,6 +301,383 @@ impl Device {
 	pub async fn amp_enable(&self, enable: bool) -> Result<()> {
 		control_out(&self.usb_if, VendorRequest::AmpEnable, 0, enable.into(), &[]).await
 	}
+
+	/// Primes the device for frequency-sweep reception.
+	///
+	/// `ranges` is a list of `(low_mhz, high_mhz)` windows to sweep across.
+	/// `step_width_hz` is the tuning step within each window, `num_bytes` is
+	/// the number of sample bytes captured per frequency (must be a multiple
+	/// of [`SWEEP_BLOCK_SIZE`]), and `offset_hz` shifts every tuned frequency
+	/// by a fixed amount.
+	///
+	/// Follow up with [`Device::set_transceiver_mode`] using
+	/// [`TransceiverMode::RxSweep`], then poll [`Device::sweep`].
+	pub async fn start_rx_sweep(
+		&self,
+		ranges: &[(u16, u16)],
+		step_width_hz: u32,
+		num_bytes: u32,
+		offset_hz: u32,
+		style: SweepStyle,
+	) -> Result<()> {
+		if num_bytes as usize % SWEEP_BLOCK_SIZE != 0 {
+			return Err(Error::Param("num_bytes"));
+		}
+
+		let mut data = Vec::with_capacity(ranges.len() * 4 + 13);
+
+		data.extend_from_slice(&step_width_hz.to_le_bytes());
+		data.extend_from_slice(&num_bytes.to_le_bytes());
+		data.extend_from_slice(&offset_hz.to_le_bytes());
+		data.push(style.into());
+
+		for &(low_mhz, high_mhz) in ranges {
+			data.extend_from_slice(&low_mhz.to_le_bytes());
+			data.extend_from_slice(&high_mhz.to_le_bytes());
+		}
+
+		control_out(&self.usb_if, VendorRequest::InitSweep, 0, 0, &data).await
+	}
+
+	/// Returns a [`Stream`] of frequency-tagged sample blocks for a sweep
+	/// started with [`Device::start_rx_sweep`].
+	///
+	/// Every USB block received while in [`TransceiverMode::RxSweep`] is
+	/// prefixed with a 10-byte header: two `0x7f` sync bytes followed by the
+	/// tuned frequency as a little-endian `u64` in Hz. This strips that
+	/// header and yields the remaining bytes of the block reinterpreted as
+	/// samples, keyed by the frequency they were captured at. A single
+	/// transfer may contain several blocks, and a header split across two
+	/// transfers is buffered until it can be parsed in full.
+	pub fn sweep(&mut self) -> Sweep<'_> {
+		Sweep { device: self, carry: Vec::new() }
+	}
+
+	/// Switches receive to ring-buffered mode: bulk-in completions are copied
+	/// into a preallocated lock-free SPSC ring as soon as they arrive instead
+	/// of handing a fresh `Vec<Sample>` to the consumer, so throughput no
+	/// longer depends on how quickly the consumer drains each transfer.
+	///
+	/// Returns a [`RxRing`] pump, which must be polled as a [`Stream`] to
+	/// keep feeding the ring, and a [`RingReader`] handle the consumer uses
+	/// to pull samples out at its own pace. `capacity` is the ring size in
+	/// bytes; if the reader falls behind and the ring fills up, the pump
+	/// yields [`Error::RxOverrun`] instead of silently dropping data.
+	pub fn rx_ring(&mut self, capacity: usize) -> (RxRing<'_>, RingReader) {
+		let (writer, reader) = ring::channel(capacity);
+
+		(RxRing { device: self, writer }, RingReader { reader })
+	}
+
+	/// Reads `len` bytes of the SPI flash starting at `address`
+	pub async fn read_flash(&self, address: u32, len: usize) -> Result<Vec<u8>> {
+		let mut out = Vec::with_capacity(len);
+
+		let mut address = address;
+		let mut remaining = len;
+
+		while remaining > 0 {
+			let chunk_len = remaining.min(FLASH_CHUNK_SIZE);
+
+			let data =
+				control_in(&self.usb_if, VendorRequest::SpiFlashRead, (address & 0xffff) as u16, (address >> 16) as u16, chunk_len as u16)
+					.await?;
+
+			out.extend_from_slice(&data);
+
+			address += chunk_len as u32;
+			remaining -= chunk_len;
+		}
+
+		Ok(out)
+	}
+
+	/// Mass-erases the SPI flash. [`Device::write_flash`] does this itself
+	/// before writing
+	pub async fn erase_flash(&self) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::SpiFlashErase, 0, 0, &[]).await
+	}
+
+	/// Reads the one-byte flash status register
+	///
+	/// Bit 0 (write-in-progress) is set while an erase or page write is still
+	/// being committed by the flash chip
+	pub async fn flash_status(&self) -> Result<u8> {
+		let res = control_in(&self.usb_if, VendorRequest::SpiFlashStatus, 0, 0, 1).await?;
+
+		let Some(&status) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		Ok(status)
+	}
+
+	/// Clears the flash status register, e.g. after a failed write
+	pub async fn clear_flash_status(&self) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::SpiFlashClearStatus, 0, 0, &[]).await
+	}
+
+	async fn await_flash_write(&self) -> Result<()> {
+		while self.flash_status().await? & FLASH_STATUS_WIP != 0 {}
+
+		Ok(())
+	}
+
+	/// Erases the flash, then streams `image` in page-sized writes, polling
+	/// [`Device::flash_status`] after each page until the write-in-progress
+	/// bit clears. Once the whole image is written, reads it back and
+	/// compares, clearing the status register and returning
+	/// [`Error::FlashVerify`] on the first mismatch.
+	pub async fn write_flash(&self, address: u32, image: &[u8]) -> Result<FlashState> {
+		self.flash_state.set(FlashState::Erasing);
+
+		let result = self.write_flash_inner(address, image).await;
+
+		if result.is_err() {
+			self.flash_state.set(FlashState::Idle);
+		}
+
+		result
+	}
+
+	async fn write_flash_inner(&self, address: u32, image: &[u8]) -> Result<FlashState> {
+		self.erase_flash().await?;
+		self.await_flash_write().await?;
+
+		self.flash_state.set(FlashState::Writing);
+
+		for (i, page) in image.chunks(FLASH_CHUNK_SIZE).enumerate() {
+			let page_addr = address + (i * FLASH_CHUNK_SIZE) as u32;
+
+			control_out(&self.usb_if, VendorRequest::SpiFlashWrite, (page_addr & 0xffff) as u16, (page_addr >> 16) as u16, page).await?;
+
+			self.await_flash_write().await?;
+		}
+
+		let readback = self.read_flash(address, image.len()).await?;
+
+		if let Some(offset) = readback.iter().zip(image).position(|(a, b)| a != b) {
+			self.clear_flash_status().await?;
+			self.flash_state.set(FlashState::Idle);
+			return Err(Error::FlashVerify { offset });
+		}
+
+		self.flash_state.set(FlashState::Verified);
+
+		Ok(FlashState::Verified)
+	}
+
+	/// Stage of the most recent [`Device::write_flash`] call
+	pub fn flash_state(&self) -> FlashState {
+		self.flash_state.get()
+	}
+
+	/// Convenience wrapper over [`Device::write_flash`] that reflashes the
+	/// whole firmware image starting at the base of the SPI flash
+	pub async fn update_firmware(&self, image: &[u8]) -> Result<FlashState> {
+		self.write_flash(0, image).await
+	}
+
+	/// Reads the checksum the CPLD computed over its own bitstream, used to
+	/// confirm a CPLD update actually took
+	pub async fn cpld_checksum(&self) -> Result<u32> {
+		let data = control_in(&self.usb_if, VendorRequest::CpldChecksum, 0, 0, 4).await?;
+
+		let bytes: [u8; 4] = data.try_into().map_err(|_| Error::Resp)?;
+
+		Ok(u32::from_le_bytes(bytes))
+	}
+
+	/// Lists the bus addresses of every Opera Cake add-on board attached to
+	/// this device
+	pub async fn opera_cake_boards(&self) -> Result<Vec<u8>> {
+		let data = control_in(&self.usb_if, VendorRequest::OperaCakeGetBoards, 0, 0, 8).await?;
+
+		Ok(data.into_iter().take_while(|&addr| addr != OPERA_CAKE_NO_BOARD).collect())
+	}
+
+	/// Manually selects the A and B ports of the Opera Cake board at `address`
+	pub async fn set_ports(&self, address: u8, a0: OperaCakePort, b0: OperaCakePort) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::OperaCakeSetPorts, address as u16, (a0 as u16) | ((b0 as u16) << 8), &[]).await
+	}
+
+	/// Sets the Opera Cake board's operating mode
+	pub async fn set_mode(&self, address: u8, mode: OperaCakeMode) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::OperaCakeSetMode, address as u16, mode as u16, &[]).await
+	}
+
+	/// Reads the Opera Cake board's operating mode
+	pub async fn get_mode(&self, address: u8) -> Result<OperaCakeMode> {
+		let res = control_in(&self.usb_if, VendorRequest::OperaCakeGetMode, address as u16, 0, 1).await?;
+
+		let Some(&mode) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		Ok(mode.into())
+	}
+
+	/// Programs the frequency windows that automatically steer the Opera
+	/// Cake's RF ports in [`OperaCakeMode::Frequency`] mode
+	pub async fn set_ranges(&self, address: u8, ranges: &[PortRange]) -> Result<()> {
+		let mut data = Vec::with_capacity(ranges.len() * 5);
+
+		for range in ranges {
+			data.extend_from_slice(&range.low_mhz.to_le_bytes());
+			data.extend_from_slice(&range.high_mhz.to_le_bytes());
+			data.push(range.port as u8);
+		}
+
+		control_out(&self.usb_if, VendorRequest::OperaCakeSetRanges, address as u16, 0, &data).await
+	}
+
+	/// Programs the per-port dwell durations used in [`OperaCakeMode::Time`]
+	/// mode, where `dwell_times` is a list of `(dwell_samples, port)` pairs
+	pub async fn set_dwell_times(&self, address: u8, dwell_times: &[(u32, OperaCakePort)]) -> Result<()> {
+		let mut data = Vec::with_capacity(dwell_times.len() * 5);
+
+		for &(dwell, port) in dwell_times {
+			data.extend_from_slice(&dwell.to_le_bytes());
+			data.push(port as u8);
+		}
+
+		control_out(&self.usb_if, VendorRequest::OperaCakeSetDwellTimes, address as u16, 0, &data).await
+	}
+
+	/// Reports whether an external 10 MHz reference is present on the CLKIN
+	/// input
+	pub async fn clkin_status(&self) -> Result<bool> {
+		let res = control_in(&self.usb_if, VendorRequest::GetClkinStatus, 0, 0, 1).await?;
+
+		let Some(&status) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		Ok(status != 0)
+	}
+
+	/// Drives the CLKOUT pin with the device's reference clock, so it can
+	/// feed CLKIN on a second unit
+	pub async fn clkout_enable(&self, enable: bool) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::ClkOutEnable, 0, enable.into(), &[]).await
+	}
+
+	/// Reads a Si5351C clock-generator register
+	pub async fn si5351c_read(&self, register: u16) -> Result<u8> {
+		let res = control_in(&self.usb_if, VendorRequest::Si5351CRead, 0, register, 1).await?;
+
+		let Some(&value) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		Ok(value)
+	}
+
+	/// Writes a Si5351C clock-generator register
+	pub async fn si5351c_write(&self, register: u16, value: u8) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::Si5351CWrite, 0, register, &[value]).await
+	}
+
+	/// Arms or disarms hardware-sync triggering for phase-coherent multi-device
+	/// capture.
+	///
+	/// # Ordering
+	/// With a shared reference clock (see [`Device::clkout_enable`]) and the
+	/// HW_SYNC line wired between boards: configure frequency and sample
+	/// rate, call `set_hw_sync_mode(true)`, and only then set
+	/// [`TransceiverMode::Receive`] on every device, so they all arm before
+	/// any of them starts sampling and the trigger edge is shared.
+	pub async fn set_hw_sync_mode(&self, enable: bool) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::SetHwSyncMode, 0, enable.into(), &[]).await
+	}
+
+	/// Sets TX VGA (Variable Gain Amplifier) gain (0-47 dB in 1 dB steps)
+	pub async fn set_txvga_gain(&self, value: u16) -> Result<()> {
+		let res = control_in(&self.usb_if, VendorRequest::SetTxVgaGain, value, 0, 1).await?;
+
+		let Some(&first) = res.first() else {
+			return Err(Error::Resp);
+		};
+
+		if first == 1 { Ok(()) } else { Err(Error::Param("Tx Vga Gain")) }
+	}
+
+	/// Toggles power on the antenna port (e.g. for an active/powered antenna)
+	pub async fn antenna_enable(&self, enable: bool) -> Result<()> {
+		control_out(&self.usb_if, VendorRequest::AntennaEnable, 0, enable.into(), &[]).await
+	}
+
+	/// Configures the antenna-port bias-tee's behavior per transceiver mode
+	/// (R9 boards only); see [`BiasTOpts`]
+	pub async fn set_bias_t(&self, opts: BiasTOpts) -> Result<()> {
+		let packed = bias_t_bits(opts.off) | (bias_t_bits(opts.rx) << 2) | (bias_t_bits(opts.tx) << 4);
+
+		control_out(&self.usb_if, VendorRequest::SetUserBiasTOpts, 0, packed, &[]).await
+	}
+}
+
+/// Packs one [`BiasTOpts`] field into the 2-bit `no-change`/`off`/`on` code
+/// the firmware expects
+fn bias_t_bits(opt: Option<bool>) -> u16 {
+	match opt {
+		None => 0,
+		Some(false) => 1,
+		Some(true) => 2,
+	}
+}
+
+/// Drains bulk-in completions into a ring buffer when polled; pair with a
+/// [`RingReader`], both returned by [`Device::rx_ring`]
+pub struct RxRing<'a> {
+	device: &'a mut Device,
+	writer: ring::Writer,
+}
+
+impl RxRing<'_> {
+	fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<()>>> {
+		let data = match ready!(self.device.poll_next_raw(cx)) {
+			Some(Ok(data)) => data,
+			Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+			None => return Poll::Ready(None),
+		};
+
+		match self.writer.write(&data) {
+			Ok(()) => Poll::Ready(Some(Ok(()))),
+			Err(ring::Overrun) => Poll::Ready(Some(Err(Error::RxOverrun))),
+		}
+	}
+}
+
+impl Stream for RxRing<'_> {
+	type Item = Result<()>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		Self::poll_next(&mut self, cx)
+	}
+}
+
+/// Consumer handle for [`Device::rx_ring`]; pulls samples out of the ring at
+/// whatever pace the caller likes, independent of the [`RxRing`] pump
+pub struct RingReader {
+	reader: ring::Reader,
+}
+
+impl RingReader {
+	/// Copies up to `out.len()` available samples out of the ring, returning
+	/// how many were actually read
+	pub fn read(&self, out: &mut [Sample]) -> usize {
+		// SAFETY: the byte view is derived from the exclusive borrow of `out`
+		// and covers exactly its `2 * out.len()` bytes; `Sample` has no
+		// padding and any byte pattern is valid for it.
+		let bytes = unsafe { std::slice::from_raw_parts_mut(out.as_mut_ptr().cast::<u8>(), out.len() * 2) };
+
+		self.reader.read(bytes) / 2
+	}
+
+	/// `true` if there are no samples currently buffered for the reader
+	pub fn is_empty(&self) -> bool {
+		self.reader.is_empty()
+	}
 }
 
 async fn control_in(interface: &nusb::Interface, vrid: VendorRequest, index: u16, value: u16, length: u16) -> Result<Vec<u8>> {
@@ -444,6 +838,89 @@ pub enum TransceiverMode {
 pub const TRANSFER_COUNT: usize = 4;
 pub const TRANSFER_SIZE: usize = 262_144;
 
+/// `num_bytes` passed to [`Device::start_rx_sweep`] must be a multiple of this
+pub const SWEEP_BLOCK_SIZE: usize = 16_384;
+
+const SWEEP_HEADER_LEN: usize = 10;
+const SWEEP_SYNC: [u8; 2] = [0x7f, 0x7f];
+
+/// Max payload for a single `SpiFlashRead`/`SpiFlashWrite` control transfer
+const FLASH_CHUNK_SIZE: usize = 64;
+
+/// Write-in-progress bit of the flash status register
+const FLASH_STATUS_WIP: u8 = 0x01;
+
+/// Stage of an in-progress [`Device::write_flash`] call
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlashState {
+	Idle,
+	Erasing,
+	Writing,
+	Verified,
+}
+
+/// Address reported by [`Device::opera_cake_boards`] for a slot with no
+/// board attached
+const OPERA_CAKE_NO_BOARD: u8 = 0xFF;
+
+/// Physical antenna port of an Opera Cake add-on board
+#[derive(Debug, Copy, Clone)]
+#[repr(u8)]
+pub enum OperaCakePort {
+	A1 = 0,
+	A2 = 1,
+	A3 = 2,
+	A4 = 3,
+	B1 = 4,
+	B2 = 5,
+	B3 = 6,
+	B4 = 7,
+}
+
+/// A `[low_mhz, high_mhz)` frequency window routed to `port`, used by
+/// [`Device::set_ranges`]
+#[derive(Debug, Copy, Clone)]
+pub struct PortRange {
+	pub low_mhz: u16,
+	pub high_mhz: u16,
+	pub port: OperaCakePort,
+}
+
+/// Opera Cake board operating mode, set with [`Device::set_mode`]
+#[derive(Debug, Copy, Clone, FromPrimitive)]
+#[repr(u8)]
+pub enum OperaCakeMode {
+	/// Ports are selected manually via [`Device::set_ports`]
+	Manual = 0,
+	/// Ports are selected automatically per [`Device::set_ranges`]
+	Frequency = 1,
+	/// Ports are cycled automatically per [`Device::set_dwell_times`]
+	Time = 2,
+	#[num_enum(default)]
+	Unrecognized = 0xFF,
+}
+
+/// Per-mode bias-tee power behavior for [`Device::set_bias_t`] (R9 boards
+/// only). Each field is `None` to leave that mode's persisted setting
+/// unchanged, or `Some(enable)` to force the bias-tee on/off while the device
+/// is in that mode.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BiasTOpts {
+	pub off: Option<bool>,
+	pub rx: Option<bool>,
+	pub tx: Option<bool>,
+}
+
+/// Sweep block ordering, passed to [`Device::start_rx_sweep`]
+#[derive(Debug, Copy, Clone, IntoPrimitive)]
+#[repr(u8)]
+pub enum SweepStyle {
+	/// Visit each range in order, low frequency to high
+	Linear = 0,
+	/// Interleave steps across all ranges
+	Interleaved = 1,
+}
+
 impl Device {
 	/// Reinforces the type when using [`futures::SinkExt`] to avoid
 	/// full-qualified path
@@ -451,7 +928,7 @@ impl Device {
 		self
 	}
 
-	fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<Sample>>>> {
+	fn poll_next_raw(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<u8>>>> {
 		while self.bulk_in.pending() < TRANSFER_COUNT {
 			self.bulk_in.submit(RequestBuffer::new(TRANSFER_SIZE));
 		}
@@ -466,9 +943,17 @@ impl Device {
 
 		self.bulk_in.submit(RequestBuffer::reuse(completion.data, TRANSFER_SIZE));
 
-		let samples = internals::bytes_into_samples(data);
+		Poll::Ready(Some(Ok(data)))
+	}
 
-		Poll::Ready(Some(Ok(samples)))
+	fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<Vec<Sample>>>> {
+		let data = match ready!(self.poll_next_raw(cx)) {
+			Some(Ok(data)) => data,
+			Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+			None => return Poll::Ready(None),
+		};
+
+		Poll::Ready(Some(Ok(internals::bytes_into_samples(data))))
 	}
 
 	#[inline]
@@ -554,6 +1039,96 @@ impl Stream for Device {
 	}
 }
 
+/// Frequency-tagged sample stream returned by [`Device::sweep`]
+pub struct Sweep<'a> {
+	device: &'a mut Device,
+	carry: Vec<u8>,
+}
+
+impl Sweep<'_> {
+	fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<(u64, Vec<Sample>)>>> {
+		loop {
+			if let Some(tile) = take_sweep_block(&mut self.carry) {
+				return Poll::Ready(Some(Ok(tile)));
+			}
+
+			match ready!(self.device.poll_next_raw(cx)) {
+				Some(Ok(data)) => self.carry.extend_from_slice(&data),
+				Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+				None => return Poll::Ready(None),
+			}
+		}
+	}
+}
+
+/// If `carry` holds a full sweep block (sync + frequency header followed by
+/// [`SWEEP_BLOCK_SIZE`] - [`SWEEP_HEADER_LEN`] bytes of samples), drains
+/// exactly that one block off the front and returns its frequency and
+/// samples. Leaves `carry` untouched otherwise, so the caller can top it up
+/// with more data and retry
+fn take_sweep_block(carry: &mut Vec<u8>) -> Option<(u64, Vec<Sample>)> {
+	if carry.len() < SWEEP_BLOCK_SIZE || !carry.starts_with(&SWEEP_SYNC) {
+		return None;
+	}
+
+	let freq = u64::from_le_bytes(carry[2..SWEEP_HEADER_LEN].try_into().unwrap());
+
+	let body_len = SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN;
+	let samples = internals::bytes_into_samples(carry[SWEEP_HEADER_LEN..SWEEP_HEADER_LEN + body_len].to_vec());
+
+	carry.drain(..SWEEP_HEADER_LEN + body_len);
+
+	Some((freq, samples))
+}
+
+#[cfg(test)]
+mod sweep_test {
+	use super::*;
+
+	/// Builds one firmware-style sweep block: sync + frequency header
+	/// followed by `SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN` bytes of sample
+	/// data, all set to `fill`
+	fn block(freq: u64, fill: u8) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(SWEEP_BLOCK_SIZE);
+		buf.extend_from_slice(&SWEEP_SYNC);
+		buf.extend_from_slice(&freq.to_le_bytes());
+		buf.resize(SWEEP_BLOCK_SIZE, fill);
+		buf
+	}
+
+	#[test]
+	fn splits_a_multi_block_transfer_into_one_tile_per_frequency() {
+		let mut carry = [block(2_400_000_000, 0xaa), block(2_500_000_000, 0xbb)].concat();
+
+		let (freq, samples) = take_sweep_block(&mut carry).unwrap();
+		assert_eq!(freq, 2_400_000_000);
+		assert_eq!(samples.len(), (SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN) / 2);
+
+		let (freq, samples) = take_sweep_block(&mut carry).unwrap();
+		assert_eq!(freq, 2_500_000_000);
+		assert_eq!(samples.len(), (SWEEP_BLOCK_SIZE - SWEEP_HEADER_LEN) / 2);
+
+		assert!(carry.is_empty());
+	}
+
+	#[test]
+	fn waits_for_a_full_block_before_draining() {
+		let mut carry = block(2_400_000_000, 0xaa);
+		carry.truncate(SWEEP_BLOCK_SIZE - 1);
+
+		assert!(take_sweep_block(&mut carry).is_none());
+		assert_eq!(carry.len(), SWEEP_BLOCK_SIZE - 1);
+	}
+}
+
+impl Stream for Sweep<'_> {
+	type Item = Result<(u64, Vec<Sample>)>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		Self::poll_next(&mut self, cx)
+	}
+}
+
 impl<T: AsRef<[Sample]>> Sink<T> for Device {
 	type Error = Error;
 
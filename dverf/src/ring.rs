@@ -0,0 +1,118 @@
+//! Lock-free single-producer/single-consumer byte ring buffer, used by
+//! [`crate::Device::rx_ring`] to decouple bulk-in completions from however
+//! fast the consumer drains them.
+
+use std::sync::{
+	Arc,
+	atomic::{AtomicPtr, AtomicUsize, Ordering},
+};
+
+struct Ring {
+	data: AtomicPtr<u8>,
+	len: usize,
+	start: AtomicUsize,
+	end: AtomicUsize,
+}
+
+impl Ring {
+	#[inline]
+	fn wrap(&self, x: usize) -> usize {
+		x % self.len
+	}
+}
+
+impl Drop for Ring {
+	fn drop(&mut self) {
+		let ptr = *self.data.get_mut();
+		let slice = std::ptr::slice_from_raw_parts_mut(ptr, self.len);
+
+		drop(unsafe { Box::from_raw(slice) });
+	}
+}
+
+/// Returned by [`Writer::write`] when the reader hasn't kept up and there
+/// isn't enough free space left in the ring
+#[derive(Debug)]
+pub struct Overrun;
+
+/// Producer half of a ring returned by [`channel`]
+pub struct Writer {
+	ring: Arc<Ring>,
+}
+
+/// Consumer half of a ring returned by [`channel`]
+pub struct Reader {
+	ring: Arc<Ring>,
+}
+
+/// Creates a ring able to hold `capacity` bytes before [`Writer::write`]
+/// starts reporting [`Overrun`]
+pub fn channel(capacity: usize) -> (Writer, Reader) {
+	// one slot is kept empty so `start == end` unambiguously means empty
+	let len = capacity + 1;
+
+	let boxed = vec![0u8; len].into_boxed_slice();
+	let ptr = Box::into_raw(boxed).cast::<u8>();
+
+	let ring = Arc::new(Ring { data: AtomicPtr::new(ptr), len, start: AtomicUsize::new(0), end: AtomicUsize::new(0) });
+
+	(Writer { ring: Arc::clone(&ring) }, Reader { ring })
+}
+
+impl Writer {
+	/// Copies `bytes` into the ring and publishes them to the reader by
+	/// advancing `end` with `Release` ordering.
+	///
+	/// If there isn't enough free space for the whole slice, nothing is
+	/// published (`end` is left untouched) and [`Overrun`] is returned.
+	pub fn write(&self, bytes: &[u8]) -> Result<(), Overrun> {
+		let ptr = self.ring.data.load(Ordering::Relaxed);
+		let mut end = self.ring.end.load(Ordering::Relaxed);
+
+		for &byte in bytes {
+			let start = self.ring.start.load(Ordering::Acquire);
+
+			if self.ring.wrap(end + 1) == start {
+				return Err(Overrun);
+			}
+
+			unsafe { ptr.add(end).write(byte) };
+			end = self.ring.wrap(end + 1);
+		}
+
+		self.ring.end.store(end, Ordering::Release);
+
+		Ok(())
+	}
+}
+
+impl Reader {
+	/// Copies up to `out.len()` bytes out of the ring, advancing `start` with
+	/// `Release` ordering, and returns how many were actually available.
+	pub fn read(&self, out: &mut [u8]) -> usize {
+		let ptr = self.ring.data.load(Ordering::Relaxed);
+		let mut start = self.ring.start.load(Ordering::Relaxed);
+		let mut read = 0;
+
+		for slot in out {
+			let end = self.ring.end.load(Ordering::Acquire);
+
+			if start == end {
+				break;
+			}
+
+			*slot = unsafe { ptr.add(start).read() };
+			start = self.ring.wrap(start + 1);
+			read += 1;
+		}
+
+		self.ring.start.store(start, Ordering::Release);
+
+		read
+	}
+
+	/// `true` if the writer has nothing buffered for the reader right now
+	pub fn is_empty(&self) -> bool {
+		self.ring.start.load(Ordering::Relaxed) == self.ring.end.load(Ordering::Acquire)
+	}
+}
@@ -4,7 +4,8 @@ use std::sync::{
 };
 
 use arc_swap::ArcSwapOption;
-use dverf::device::{BoardId, BoardRev, Device, VENDOR_ID};
+use dverf::device::{Device, VENDOR_ID};
+use dverf_device::{BoardId as DemoBoardId, BoardRev as DemoBoardRev, Device as DemoDevice, MockTransport};
 use futures::future;
 use futures_concurrency::future::TryJoin;
 use nusb::{DeviceId, DeviceInfo, hotplug::HotplugEvent};
@@ -18,6 +19,19 @@ pub struct Backend {
 }
 
 impl Backend {
+	/// Backend for egui's demo mode: skips hotplug watching entirely and
+	/// reports a single canned device, queried through a
+	/// [`dverf_device::MockTransport`] with the same board id, board rev and
+	/// version requests that real hardware is asked through `dverf::Device`
+	pub fn demo() -> Arc<Self> {
+		let backend = Arc::new(Self::default());
+
+		backend.device_description.store(Some(Arc::new(smol::block_on(demo_description()))));
+		backend.connected.store(true, Ordering::Relaxed);
+
+		backend
+	}
+
 	pub async fn run(self: Arc<Self>) {
 		let device_task = smol::spawn({
 			let slf = Arc::clone(&self);
@@ -65,16 +79,18 @@ impl Backend {
 }
 
 pub struct DeviceDescription {
-	pub id: DeviceId,
-	pub info: DeviceInfo,
-	pub board_id: BoardId,
-	pub board_rev: BoardRev,
+	/// `None` for the device built by [`Backend::demo`], which has no real
+	/// USB identity or enumeration info behind it
+	pub id: Option<DeviceId>,
+	pub info: Option<DeviceInfo>,
+	pub board_id: String,
+	pub board_rev: String,
 	pub version: String,
 }
 
 impl DeviceDescription {
 	fn is(&self, id: DeviceId) -> bool {
-		self.id == id
+		self.id == Some(id)
 	}
 }
 
@@ -107,12 +123,29 @@ async fn try_open_device(device_info: &DeviceInfo) -> Option<(DeviceDescription,
 
 	Some((
 		DeviceDescription {
-			id: device_info.id().clone(),
-			info: device_info.clone(),
-			board_id: id,
-			board_rev: rev,
+			id: Some(device_info.id().clone()),
+			info: Some(device_info.clone()),
+			board_id: id.to_string(),
+			board_rev: rev.to_string(),
 			version,
 		},
 		dev,
 	))
 }
+
+async fn demo_description() -> DeviceDescription {
+	let dev = DemoDevice::from_transport(
+		MockTransport::new().with_board(DemoBoardId::HackrfOneR9, DemoBoardRev::R9).with_version("demo-1.0.0"),
+	);
+
+	// The mock transport never fails a control request
+	let (board_id, board_rev, version) = (dev.board_id(), dev.board_rev(), dev.version()).try_join().await.expect("mock transport request failed");
+
+	DeviceDescription {
+		id: None,
+		info: None,
+		board_id: format!("{board_id:?} (demo)"),
+		board_rev: format!("{board_rev:?}"),
+		version,
+	}
+}
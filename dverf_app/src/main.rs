@@ -9,13 +9,19 @@ use crate::backend::Backend;
 fn main() -> eframe::Result {
 	trace::setup();
 
-	let backend = Arc::new(Backend::default());
-
-	smol::spawn({
-		let backend = Arc::clone(&backend);
-		backend.run()
-	})
-	.detach();
+	let backend = if std::env::args().any(|arg| arg == "--demo") {
+		Backend::demo()
+	} else {
+		let backend = Arc::new(Backend::default());
+
+		smol::spawn({
+			let backend = Arc::clone(&backend);
+			backend.run()
+		})
+		.detach();
+
+		backend
+	};
 
 	let options = eframe::NativeOptions {
 		viewport: egui::ViewportBuilder::default().with_inner_size([1280.0, 1024.0]).with_drag_and_drop(true),
@@ -49,23 +55,23 @@ impl eframe::App for App {
 				if let Some(descr) = self.backend.device_description() {
 					egui::Grid::new("device_info").striped(true).show(ui, |ui| {
 						ui.label("Title");
-						ui.label(descr.info.product_string().unwrap_or("unknown"));
+						ui.label(descr.info.as_ref().and_then(|info| info.product_string()).unwrap_or("demo device"));
 						ui.end_row();
 
 						ui.label("Manufacturer");
-						ui.label(descr.info.manufacturer_string().unwrap_or("unknown"));
+						ui.label(descr.info.as_ref().and_then(|info| info.manufacturer_string()).unwrap_or("unknown"));
 						ui.end_row();
 
 						ui.label("Product Id");
-						ui.label(format!("{:04x}", descr.info.product_id()));
+						ui.label(descr.info.as_ref().map_or("n/a".to_owned(), |info| format!("{:04x}", info.product_id())));
 						ui.end_row();
 
 						ui.label("Board Id");
-						ui.label(descr.board_id.to_string());
+						ui.label(&descr.board_id);
 						ui.end_row();
 
 						ui.label("Board Rev");
-						ui.label(descr.board_rev.to_string());
+						ui.label(&descr.board_rev);
 						ui.end_row();
 
 						ui.label("Fw Version");